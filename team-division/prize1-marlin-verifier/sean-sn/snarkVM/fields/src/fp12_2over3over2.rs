@@ -53,6 +53,12 @@ pub struct Fp12<P: Fp12Parameters> {
     pub c1: Fp6<P::Fp6Params>,
 }
 
+// With the `blst` feature (on by default) these operations call into the
+// blst static library. Without it, targets where blst is unavailable
+// (wasm32, some embedded/CI sandboxes) fall back to a pure-Rust
+// implementation built on the Fp6/Fp2 tower below. Both paths are required
+// to agree bit-for-bit.
+#[cfg(feature = "blst")]
 extern "C" {
     fn blst_fp12_377_mul(ret: *mut c_void, a: *const c_void, b: *const c_void);
     fn blst_fp12_377_sqr(ret: *mut c_void, a: *const c_void);
@@ -65,6 +71,40 @@ extern "C" {
 
 type Fp2Params<P> = <<P as Fp12Parameters>::Fp6Params as Fp6Parameters>::Fp2Params;
 
+/// Montgomery batch inversion, generic over any [`Field`]: a single
+/// `F::inverse` call plus `O(n)` multiplications, instead of `n`
+/// independent field inversions. Elements that are zero are left
+/// untouched (they have no inverse). Shared by [`Fp12::batch_inverse`]
+/// and [`CompressedFp12::batch_decompress`].
+fn montgomery_batch_inverse<F: Field>(elements: &mut [F]) {
+    // Walk forward building the prefix products p_i = a_1 * ... * a_i,
+    // skipping zero elements so they don't poison the running product.
+    let mut prefix_products = Vec::with_capacity(elements.len());
+    let mut running_product = F::one();
+    for element in elements.iter().filter(|element| !element.is_zero()) {
+        running_product *= element;
+        prefix_products.push(running_product);
+    }
+
+    // Invert the product of all nonzero elements in a single call. If
+    // every element was zero, `running_product` is still `one` and
+    // there is nothing left to do.
+    let mut running_inverse = running_product.inverse().unwrap();
+
+    // Walk backward, recovering a_i^{-1} = running_inverse * p_{i-1}
+    // (with p_0 = 1), then updating running_inverse *= a_i.
+    for (element, prefix_product) in elements
+        .iter_mut()
+        .rev()
+        .filter(|element| !element.is_zero())
+        .zip(prefix_products.into_iter().rev().skip(1).chain(Some(F::one())))
+    {
+        let next_running_inverse = running_inverse * *element;
+        *element = running_inverse * prefix_product;
+        running_inverse = next_running_inverse;
+    }
+}
+
 impl<P: Fp12Parameters> Fp12<P> {
     /// Multiply by quadratic nonresidue v.
     #[inline(always)]
@@ -85,7 +125,22 @@ impl<P: Fp12Parameters> Fp12<P> {
     }
 
     pub fn conjugate(&mut self) {
-        unsafe { blst_fp12_377_conjugate(self as *mut Self as *mut c_void) };
+        #[cfg(feature = "blst")]
+        unsafe {
+            blst_fp12_377_conjugate(self as *mut Self as *mut c_void)
+        };
+
+        #[cfg(not(feature = "blst"))]
+        {
+            self.conjugate_native();
+        }
+    }
+
+    /// Conjugation on Fp12 = Fp6[w]/(w^2 - v) is the Frobenius^6 map, which
+    /// fixes Fp6 and sends w to -w.
+    #[allow(dead_code)]
+    fn conjugate_native(&mut self) {
+        self.c1 = -self.c1;
     }
 
     pub fn mul_by_034(&mut self, c0: &Fp2<Fp2Params<P>>, c3: &Fp2<Fp2Params<P>>, c4: &Fp2<Fp2Params<P>>) {
@@ -121,14 +176,150 @@ impl<P: Fp12Parameters> Fp12<P> {
     }
 
     pub fn cyclotomic_square(&self) -> Self {
-        let mut result = Self::zero();
-        unsafe {
-            blst_fp12_377_cyclotomic_sqr(
-                &mut result as *mut Self as *mut c_void,
-                self as *const Self as *const c_void,
-            )
-        };
-        result
+        #[cfg(feature = "blst")]
+        {
+            let mut result = Self::zero();
+            unsafe {
+                blst_fp12_377_cyclotomic_sqr(
+                    &mut result as *mut Self as *mut c_void,
+                    self as *const Self as *const c_void,
+                )
+            };
+            result
+        }
+
+        #[cfg(not(feature = "blst"))]
+        {
+            self.cyclotomic_square_native()
+        }
+    }
+
+    /// Granger-Scott cyclotomic squaring, working on the six Fp2
+    /// coordinates (z0..z5) = ((c0.c0, c1.c1, c1.c0), (c0.c2, c0.c1, c1.c2)).
+    #[allow(dead_code)]
+    fn cyclotomic_square_native(&self) -> Self {
+        let fp2_nr = <P::Fp6Params as Fp6Parameters>::mul_fp2_by_nonresidue;
+
+        let mut z0 = self.c0.c0;
+        let mut z4 = self.c0.c1;
+        let mut z3 = self.c0.c2;
+        let mut z2 = self.c1.c0;
+        let mut z1 = self.c1.c1;
+        let mut z5 = self.c1.c2;
+
+        // t0 + t1*y = (z0 + z1*y)^2
+        let mut tmp = z0 * z1;
+        let t0 = (z0 + z1) * (z0 + fp2_nr(&z1)) - tmp - fp2_nr(&tmp);
+        let t1 = tmp.double();
+
+        // t2 + t3*y = (z2 + z3*y)^2
+        tmp = z2 * z3;
+        let t2 = (z2 + z3) * (z2 + fp2_nr(&z3)) - tmp - fp2_nr(&tmp);
+        let t3 = tmp.double();
+
+        // t4 + t5*y = (z4 + z5*y)^2
+        tmp = z4 * z5;
+        let t4 = (z4 + z5) * (z4 + fp2_nr(&z5)) - tmp - fp2_nr(&tmp);
+        let t5 = tmp.double();
+
+        z0 = t0 - z0;
+        z0 = z0.double() + t0;
+
+        z1 = t1 + z1;
+        z1 = z1.double() + t1;
+
+        tmp = fp2_nr(&t5);
+        z2 = tmp + z2;
+        z2 = z2.double() + tmp;
+
+        z3 = t4 - z3;
+        z3 = z3.double() + t4;
+
+        z4 = t2 - z4;
+        z4 = z4.double() + t2;
+
+        z5 = t3 + z5;
+        z5 = z5.double() + t3;
+
+        Self::new(Fp6::new(z0, z4, z3), Fp6::new(z2, z1, z5))
+    }
+
+    #[allow(dead_code)]
+    fn is_one_native(&self) -> bool {
+        self.c1.is_zero() && self.c0.is_one()
+    }
+
+    #[allow(dead_code)]
+    fn frobenius_map_native(&mut self, power: usize) {
+        self.c0.frobenius_map(power);
+        self.c1.frobenius_map(power);
+
+        self.c1.c0.mul_assign(&P::FROBENIUS_COEFF_FP12_C1[power % 12]);
+        self.c1.c1.mul_assign(&P::FROBENIUS_COEFF_FP12_C1[power % 12]);
+        self.c1.c2.mul_assign(&P::FROBENIUS_COEFF_FP12_C1[power % 12]);
+    }
+
+    /// (c0 + c1*w)^2 = (c0^2 + v*c1^2) + 2*c0*c1*w.
+    #[allow(dead_code)]
+    fn square_native(&self) -> Self {
+        let aa = self.c0 * self.c0;
+        let bb = self.c1 * self.c1;
+        let c0 = aa + Self::mul_fp6_by_nonresidue(&bb);
+        let c1 = (self.c0 + self.c1) * (self.c0 + self.c1) - aa - bb;
+        Self::new(c0, c1)
+    }
+
+    /// m^-1 = (c0 - c1*w) / (c0^2 - v*c1^2).
+    #[allow(dead_code)]
+    fn inverse_native(&self) -> Option<Self> {
+        let norm = self.c0 * self.c0 - Self::mul_fp6_by_nonresidue(&(self.c1 * self.c1));
+        norm.inverse().map(|norm_inv| Self::new(self.c0 * norm_inv, -(self.c1 * norm_inv)))
+    }
+
+    /// Karatsuba multiplication: (c0 + c1*w)(d0 + d1*w)
+    ///   = (c0*d0 + v*c1*d1) + ((c0+c1)*(d0+d1) - c0*d0 - c1*d1)*w.
+    #[allow(dead_code)]
+    fn mul_native(&self, other: &Self) -> Self {
+        let aa = self.c0 * other.c0;
+        let bb = self.c1 * other.c1;
+        let c0 = aa + Self::mul_fp6_by_nonresidue(&bb);
+        let c1 = (self.c0 + self.c1) * (other.c0 + other.c1) - aa - bb;
+        Self::new(c0, c1)
+    }
+
+    /// Compresses `self` into the Karabina representation, keeping only the
+    /// four coordinates (g2, g3, g4, g5) of the six that make up an element
+    /// of the order-r cyclotomic subgroup. Callers are responsible for
+    /// ensuring `self` is actually cyclotomic; no check is performed here.
+    pub fn compress(&self) -> CompressedFp12<P> {
+        CompressedFp12 {
+            g2: self.c1.c0,
+            g3: self.c0.c2,
+            g4: self.c0.c1,
+            g5: self.c1.c2,
+            is_minus_one: *self == -Self::one(),
+        }
+    }
+
+    /// Inverts many elements at once using Montgomery's trick: a single
+    /// [`Field::inverse`] call (`blst_fp12_377_inverse` with the `blst`
+    /// feature) plus `O(n)` multiplications, instead of
+    /// `n` independent field inversions. Elements that are zero are left
+    /// untouched (they have no inverse).
+    pub fn batch_inverse(elements: &mut [Self]) {
+        montgomery_batch_inverse(elements);
+    }
+
+    /// Non-mutating variant of [`Self::batch_inverse`]. Returns `None` for
+    /// elements that are zero and `Some(inverse)` for all others.
+    pub fn batch_inverse_into_vec(elements: &[Self]) -> Vec<Option<Self>> {
+        let mut inverses: Vec<Self> = elements.to_vec();
+        Self::batch_inverse(&mut inverses);
+        elements
+            .iter()
+            .zip(inverses.into_iter())
+            .map(|(original, maybe_inverse)| if original.is_zero() { None } else { Some(maybe_inverse) })
+            .collect()
     }
 
     pub fn cyclotomic_exp<S: AsRef<[u64]>>(&self, exp: S) -> Self {
@@ -153,6 +344,246 @@ impl<P: Fp12Parameters> Fp12<P> {
         }
         res
     }
+
+    /// Width-`window` NAF variant of [`Self::cyclotomic_exp`]. Inversion in
+    /// the cyclotomic subgroup is just [`Self::conjugate`], so recoding the
+    /// exponent into signed non-adjacent-form digits and precomputing only
+    /// the odd positive powers of `self` roughly halves the number of
+    /// multiplications for large fixed exponents such as the BLS12-377
+    /// final-exponentiation hard part.
+    pub fn cyclotomic_exp_naf<S: AsRef<[u64]>>(&self, exp: S, window: usize) -> Self {
+        assert!(window >= 2, "NAF window must be at least 2");
+
+        let naf = find_wnaf(window, exp.as_ref());
+
+        // Table of odd positive powers self^1, self^3, ..., self^(2^(window - 1) - 1).
+        let self_squared = self.cyclotomic_square();
+        let mut table = Vec::with_capacity(1 << (window - 2));
+        table.push(*self);
+        for i in 1..(1 << (window - 2)) {
+            table.push(table[i - 1] * self_squared);
+        }
+
+        let mut res = Self::one();
+        let mut found_nonzero = false;
+        for &digit in naf.iter().rev() {
+            if found_nonzero {
+                res = res.cyclotomic_square();
+            }
+
+            if digit != 0 {
+                found_nonzero = true;
+                let mut multiplicand = table[(digit.unsigned_abs() as usize - 1) / 2];
+                if digit < 0 {
+                    multiplicand.conjugate();
+                }
+                res *= &multiplicand;
+            }
+        }
+        res
+    }
+}
+
+/// Recodes `exp` (little-endian limbs) into signed width-`window`
+/// non-adjacent form: digits are odd and drawn from
+/// `{-(2^(window-1) - 1), ..., -1, 1, ..., 2^(window-1) - 1}`, with `0`
+/// filling the positions in between. Returned least-significant digit first.
+fn find_wnaf(window: usize, exp: &[u64]) -> Vec<i64> {
+    // NAF recoding can borrow a digit past the input's highest set bit (e.g.
+    // recoding `0xFF...FF` rounds up into a carry above the top limb), so a
+    // num that exactly fills its limb slice needs one extra zero limb of
+    // headroom or that carry silently drops off the end.
+    let mut num = exp.to_vec();
+    num.push(0);
+    let mut naf = vec![];
+
+    let is_zero = |num: &[u64]| num.iter().all(|&limb| limb == 0);
+    let div2 = |num: &mut [u64]| {
+        let mut carry = 0u64;
+        for limb in num.iter_mut().rev() {
+            let new_carry = *limb & 1;
+            *limb = (*limb >> 1) | (carry << 63);
+            carry = new_carry;
+        }
+    };
+    let sub_small = |num: &mut [u64], mut value: u64| {
+        for limb in num.iter_mut() {
+            let (res, borrow) = limb.overflowing_sub(value);
+            *limb = res;
+            value = borrow as u64;
+            if value == 0 {
+                break;
+            }
+        }
+    };
+    let add_small = |num: &mut [u64], mut value: u64| {
+        for limb in num.iter_mut() {
+            let (res, carry) = limb.overflowing_add(value);
+            *limb = res;
+            value = carry as u64;
+            if value == 0 {
+                break;
+            }
+        }
+    };
+
+    while !is_zero(&num) {
+        let digit = if num[0] & 1 == 1 {
+            let modulus = 1u64 << window;
+            let mut z = (num[0] % modulus) as i64;
+            if z >= 1 << (window - 1) {
+                z -= modulus as i64;
+            }
+            if z >= 0 {
+                sub_small(&mut num, z as u64);
+            } else {
+                add_small(&mut num, (-z) as u64);
+            }
+            z
+        } else {
+            0
+        };
+        naf.push(digit);
+        div2(&mut num);
+    }
+
+    naf
+}
+
+/// A Karabina-compressed element of the order-r cyclotomic subgroup of
+/// `Fp12`. Only four of the six underlying `Fp2` coordinates (g2, g3, g4,
+/// g5) are stored; g0 and g1 are recovered on [`Self::decompress`]. This
+/// cuts the memory footprint of repeated `cyclotomic_square` chains, such
+/// as the final-exponentiation hard part, to two thirds of the full element.
+#[derive(Derivative)]
+#[derivative(
+    Default(bound = "P: Fp12Parameters"),
+    Clone(bound = "P: Fp12Parameters"),
+    Copy(bound = "P: Fp12Parameters"),
+    Debug(bound = "P: Fp12Parameters"),
+    PartialEq(bound = "P: Fp12Parameters"),
+    Eq(bound = "P: Fp12Parameters")
+)]
+pub struct CompressedFp12<P: Fp12Parameters> {
+    pub g2: Fp2<Fp2Params<P>>,
+    pub g3: Fp2<Fp2Params<P>>,
+    pub g4: Fp2<Fp2Params<P>>,
+    pub g5: Fp2<Fp2Params<P>>,
+    /// Within the order-r cyclotomic subgroup, `g2 == g3 == 0` forces `g4 ==
+    /// g5 == 0` too and the element is `±1` -- the one case the four
+    /// coordinates above cannot distinguish on their own. This flag breaks
+    /// the tie; it is otherwise always `false`.
+    is_minus_one: bool,
+}
+
+impl<P: Fp12Parameters> CompressedFp12<P> {
+    /// Squares the compressed element using the Karabina formulas, working
+    /// directly on (g2, g3, g4, g5) without ever reconstructing g0 or g1.
+    pub fn square(&self) -> Self {
+        // The fast compressed formula divides implicitly on decompression
+        // whenever g2 == 0; rather than risk it silently misbehaving in
+        // that corner, fall back to decompressing, squaring in full (which
+        // has no such restriction), and recompressing. `±1` squares to `1`
+        // directly without needing either path.
+        if self.g2.is_zero() && self.g3.is_zero() {
+            return Self { g2: self.g2, g3: self.g3, g4: self.g4, g5: self.g5, is_minus_one: false };
+        }
+        if self.g2.is_zero() {
+            return self.decompress().cyclotomic_square().compress();
+        }
+
+        let fp2_nr = <P::Fp6Params as Fp6Parameters>::mul_fp2_by_nonresidue;
+
+        let mut tmp = self.g2 * self.g3;
+        let t2 = (self.g2 + self.g3) * (self.g2 + fp2_nr(&self.g3)) - tmp - fp2_nr(&tmp);
+        let t3 = tmp.double();
+
+        tmp = self.g4 * self.g5;
+        let t4 = (self.g4 + self.g5) * (self.g4 + fp2_nr(&self.g5)) - tmp - fp2_nr(&tmp);
+        let t5 = tmp.double();
+
+        let g2 = fp2_nr(&t5) + fp2_nr(&t5) + fp2_nr(&t5) + self.g2 + self.g2;
+        let g3 = t4 + t4 + t4 - self.g3 - self.g3;
+        let g4 = t2 + t2 + t2 - self.g4 - self.g4;
+        let g5 = t3 + t3 + t3 + self.g5 + self.g5;
+
+        Self { g2, g3, g4, g5, is_minus_one: false }
+    }
+
+    /// Recovers the full six-coordinate `Fp12` element. Requires one field
+    /// inversion; use [`Self::batch_decompress`] to amortize that cost when
+    /// decompressing many elements at once.
+    pub fn decompress(&self) -> Fp12<P> {
+        if self.g2.is_zero() && self.g3.is_zero() {
+            return if self.is_minus_one { -Fp12::one() } else { Fp12::one() };
+        }
+
+        let fp2_nr = <P::Fp6Params as Fp6Parameters>::mul_fp2_by_nonresidue;
+
+        let g1 = if !self.g2.is_zero() {
+            let numerator = fp2_nr(&self.g5.square()) + self.g4.square().double() + self.g4.square() - self.g3.double();
+            numerator * self.g2.double().double().inverse().unwrap()
+        } else {
+            (self.g4 * self.g5).double() * self.g3.inverse().unwrap()
+        };
+
+        let g0 = fp2_nr(&(g1.square().double() + self.g2 * self.g5 - (self.g3 * self.g4).double() - self.g3 * self.g4))
+            + Fp2::<Fp2Params<P>>::one();
+
+        Fp12::new(Fp6::new(g0, self.g4, self.g3), Fp6::new(self.g2, g1, self.g5))
+    }
+
+    /// Decompresses many elements at once, inverting all the required
+    /// denominators with a single Montgomery batch inversion instead of one
+    /// inversion per element. The degenerate `±1` elements (`g2 == g3 ==
+    /// 0`) carry no such denominator and are resolved directly, so a stray
+    /// zero never enters the batch inversion.
+    pub fn batch_decompress(compressed: &[Self]) -> Vec<Fp12<P>> {
+        let fp2_nr = <P::Fp6Params as Fp6Parameters>::mul_fp2_by_nonresidue;
+
+        let is_degenerate: Vec<bool> = compressed.iter().map(|c| c.g2.is_zero() && c.g3.is_zero()).collect();
+
+        // Denominators for the degenerate entries are meaningless; give
+        // them `one` as a harmless placeholder so they never touch the
+        // skip-zero logic in `montgomery_batch_inverse`.
+        let mut denominators: Vec<Fp2<Fp2Params<P>>> = compressed
+            .iter()
+            .zip(is_degenerate.iter())
+            .map(|(c, &degenerate)| {
+                if degenerate {
+                    Fp2::<Fp2Params<P>>::one()
+                } else if !c.g2.is_zero() {
+                    c.g2.double().double()
+                } else {
+                    c.g3
+                }
+            })
+            .collect();
+        montgomery_batch_inverse(&mut denominators);
+
+        compressed
+            .iter()
+            .zip(is_degenerate.into_iter())
+            .zip(denominators.into_iter())
+            .map(|((c, degenerate), denominator_inverse)| {
+                if degenerate {
+                    return if c.is_minus_one { -Fp12::one() } else { Fp12::one() };
+                }
+
+                let g1 = if !c.g2.is_zero() {
+                    let numerator = fp2_nr(&c.g5.square()) + c.g4.square().double() + c.g4.square() - c.g3.double();
+                    numerator * denominator_inverse
+                } else {
+                    (c.g4 * c.g5).double() * denominator_inverse
+                };
+
+                let g0 =
+                    fp2_nr(&(g1.square().double() + c.g2 * c.g5 - (c.g3 * c.g4).double() - c.g3 * c.g4)) + Fp2::<Fp2Params<P>>::one();
+
+                Fp12::new(Fp6::new(g0, c.g4, c.g3), Fp6::new(c.g2, g1, c.g5))
+            })
+            .collect()
+    }
 }
 
 impl<P: Fp12Parameters> std::fmt::Display for Fp12<P> {
@@ -184,7 +615,15 @@ impl<P: Fp12Parameters> One for Fp12<P> {
     }
 
     fn is_one(&self) -> bool {
-        unsafe { blst_fp12_377_is_one(self as *const Self as *const c_void) }
+        #[cfg(feature = "blst")]
+        unsafe {
+            return blst_fp12_377_is_one(self as *const Self as *const c_void);
+        }
+
+        #[cfg(not(feature = "blst"))]
+        {
+            self.is_one_native()
+        }
     }
 }
 
@@ -228,6 +667,7 @@ impl<P: Fp12Parameters> Field for Fp12<P> {
     }
 
     fn frobenius_map(&mut self, power: usize) {
+        #[cfg(feature = "blst")]
         if power > 0 && power <= 3 {
             unsafe {
                 blst_fp12_377_frobenius_map(
@@ -238,39 +678,49 @@ impl<P: Fp12Parameters> Field for Fp12<P> {
             };
             return;
         }
-        self.c0.frobenius_map(power);
-        self.c1.frobenius_map(power);
-
-        self.c1.c0.mul_assign(&P::FROBENIUS_COEFF_FP12_C1[power % 12]);
-        self.c1.c1.mul_assign(&P::FROBENIUS_COEFF_FP12_C1[power % 12]);
-        self.c1.c2.mul_assign(&P::FROBENIUS_COEFF_FP12_C1[power % 12]);
+        self.frobenius_map_native(power);
     }
 
     fn square(&self) -> Self {
-        let mut result = Self::zero();
-        unsafe {
-            blst_fp12_377_sqr(
-                &mut result as *mut Self as *mut c_void,
-                self as *const Self as *const c_void,
-            )
-        };
-        result
+        #[cfg(feature = "blst")]
+        {
+            let mut result = Self::zero();
+            unsafe {
+                blst_fp12_377_sqr(
+                    &mut result as *mut Self as *mut c_void,
+                    self as *const Self as *const c_void,
+                )
+            };
+            result
+        }
+
+        #[cfg(not(feature = "blst"))]
+        {
+            self.square_native()
+        }
     }
 
     fn square_in_place(&mut self) -> &mut Self {
+        #[cfg(feature = "blst")]
         unsafe {
-            blst_fp12_377_sqr(
-                self as *mut Self as *mut c_void,
-                self as *const Self as *const c_void,
-            )
+            blst_fp12_377_sqr(self as *mut Self as *mut c_void, self as *const Self as *const c_void)
         };
+
+        #[cfg(not(feature = "blst"))]
+        {
+            *self = self.square();
+        }
+
         self
     }
 
     fn inverse(&self) -> Option<Self> {
         if self.is_zero() {
-            None
-        } else {
+            return None;
+        }
+
+        #[cfg(feature = "blst")]
+        {
             let mut result = Self::zero();
             unsafe {
                 blst_fp12_377_inverse(
@@ -280,6 +730,11 @@ impl<P: Fp12Parameters> Field for Fp12<P> {
             };
             Some(result)
         }
+
+        #[cfg(not(feature = "blst"))]
+        {
+            self.inverse_native()
+        }
     }
 
     fn inverse_in_place(&mut self) -> Option<&mut Self> {
@@ -335,15 +790,23 @@ impl<'a, P: Fp12Parameters> Mul<&'a Self> for Fp12<P> {
 
     #[inline]
     fn mul(self, other: &Self) -> Self {
-        let mut result = Self::zero();
-        unsafe {
-            blst_fp12_377_mul(
-                &mut result as *mut Self as *mut c_void,
-                &self as *const Self as *const c_void,
-                other as *const Self as *const c_void,
-            )
-        };
-        result
+        #[cfg(feature = "blst")]
+        {
+            let mut result = Self::zero();
+            unsafe {
+                blst_fp12_377_mul(
+                    &mut result as *mut Self as *mut c_void,
+                    &self as *const Self as *const c_void,
+                    other as *const Self as *const c_void,
+                )
+            };
+            result
+        }
+
+        #[cfg(not(feature = "blst"))]
+        {
+            self.mul_native(other)
+        }
     }
 }
 
@@ -378,6 +841,7 @@ impl<'a, P: Fp12Parameters> MulAssign<&'a Self> for Fp12<P> {
     #[inline]
     #[allow(clippy::suspicious_op_assign_impl)]
     fn mul_assign(&mut self, other: &Self) {
+        #[cfg(feature = "blst")]
         unsafe {
             blst_fp12_377_mul(
                 self as *mut Self as *mut c_void,
@@ -385,6 +849,11 @@ impl<'a, P: Fp12Parameters> MulAssign<&'a Self> for Fp12<P> {
                 other as *const Self as *const c_void,
             )
         };
+
+        #[cfg(not(feature = "blst"))]
+        {
+            *self = self.mul_native(other);
+        }
     }
 }
 
@@ -486,6 +955,57 @@ impl<P: Fp12Parameters> CanonicalSerializeWithFlags for Fp12<P> {
     }
 }
 
+/// Flags packed into the last byte of a torus-compressed `Fp12` (GT)
+/// encoding. `One`/`MinusOne` mark the degenerate case `c1 = 0`, where the
+/// torus map `g = (c0 + 1) * c1^{-1}` is undefined and no Fp6 payload is
+/// meaningful.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum GTFlags {
+    /// `self = g`, the torus-compressed representative of a cyclotomic element.
+    Full,
+    /// `self = 1`.
+    One,
+    /// `self = -1`.
+    MinusOne,
+}
+
+impl Default for GTFlags {
+    #[inline]
+    fn default() -> Self {
+        GTFlags::Full
+    }
+}
+
+impl Flags for GTFlags {
+    const BIT_SIZE: usize = 2;
+
+    #[inline]
+    fn u8_bitmask(&self) -> u8 {
+        match self {
+            GTFlags::Full => 0,
+            GTFlags::One => 1,
+            GTFlags::MinusOne => 2,
+        }
+    }
+
+    #[inline]
+    fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(GTFlags::Full),
+            1 => Some(GTFlags::One),
+            2 => Some(GTFlags::MinusOne),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn from_u8_remove_flags(value: &mut u8) -> Option<Self> {
+        let flags = Self::from_u8(*value & 0b11)?;
+        *value >>= Self::BIT_SIZE;
+        Some(flags)
+    }
+}
+
 impl<P: Fp12Parameters> CanonicalSerialize for Fp12<P> {
     #[inline]
     fn serialize_with_mode<W: Write>(&self, writer: W, _compress: Compress) -> Result<(), SerializationError> {
@@ -532,3 +1052,207 @@ impl<P: Fp12Parameters> CanonicalDeserialize for Fp12<P> {
         Ok(Fp12::new(c0, c1))
     }
 }
+
+/// A pairing-output (GT) element, i.e. an `Fp12` known to lie in the order-r
+/// cyclotomic subgroup. `Fp12<P>` itself is the generic tower field used
+/// throughout the pairing stack and must accept arbitrary values, so the
+/// torus compression and the cyclotomic-subgroup validity check both live
+/// here instead of on `Fp12<P>` directly.
+#[derive(Derivative)]
+#[derivative(
+    Default(bound = "P: Fp12Parameters"),
+    Clone(bound = "P: Fp12Parameters"),
+    Copy(bound = "P: Fp12Parameters"),
+    Debug(bound = "P: Fp12Parameters"),
+    PartialEq(bound = "P: Fp12Parameters"),
+    Eq(bound = "P: Fp12Parameters")
+)]
+pub struct Gt<P: Fp12Parameters>(pub Fp12<P>);
+
+impl<P: Fp12Parameters> CanonicalSerialize for Gt<P> {
+    #[inline]
+    fn serialize_with_mode<W: Write>(&self, mut writer: W, compress: Compress) -> Result<(), SerializationError> {
+        match compress {
+            Compress::No => self.0.serialize_with_mode(writer, compress),
+            // Torus (T2) compression: a cyclotomic `m = c0 + c1 * w` is
+            // represented by the single Fp6 element `g = (c0 + 1) * c1^-1`,
+            // halving the number of Fp6 limbs written to the wire. The
+            // degenerate case `c1 = 0` (`m = ±1`) has no such `g` and is
+            // instead signalled with a reserved flag.
+            Compress::Yes => {
+                let m = self.0;
+                if m.c1.is_zero() {
+                    let flags = if m.c0.is_one() { GTFlags::One } else { GTFlags::MinusOne };
+                    Fp6::zero().serialize_with_flags(&mut writer, flags)
+                } else {
+                    let g = (m.c0 + Fp6::one()) * m.c1.inverse().unwrap();
+                    g.serialize_with_flags(&mut writer, GTFlags::Full)
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn serialized_size(&self, compress: Compress) -> usize {
+        match compress {
+            Compress::No => self.0.serialized_size(compress),
+            Compress::Yes => self.0.c0.serialized_size_with_flags::<GTFlags>(),
+        }
+    }
+}
+
+impl<P: Fp12Parameters> Valid for Gt<P> {
+    fn check(&self) -> Result<(), snarkvm_utilities::SerializationError> {
+        let m = self.0;
+
+        // Unitarity (`m * conjugate(m) == 1`) holds for any value reachable
+        // via the torus map `g -> (g+w)/(g-w)`, invalid or not -- it's an
+        // algebraic identity of that map, not a subgroup-specific property.
+        // What this checks instead is the Frobenius relation
+        // `m^(p^4) * m == m^(p^2)` (equivalent to `m^(p^4 - p^2 + 1) == 1`),
+        // i.e. membership in the cyclotomic subgroup of order
+        // `p^4 - p^2 + 1`. Every element of the order-r subgroup satisfies
+        // it, but it does not by itself reject elements of that larger
+        // subgroup whose order is not r. `frobenius_map` computes the `p^k`-power maps
+        // directly, so this is cheap to check without a full exponentiation.
+        let mut m_p2 = m;
+        m_p2.frobenius_map(2);
+        let mut m_p4 = m;
+        m_p4.frobenius_map(4);
+        if m_p4 * &m == m_p2 { Ok(()) } else { Err(SerializationError::InvalidData) }
+    }
+
+    fn batch_check<'a>(batch: impl Iterator<Item = &'a Self>) -> Result<(), snarkvm_utilities::SerializationError>
+    where
+        Self: 'a,
+    {
+        for element in batch {
+            element.check()?;
+        }
+        Ok(())
+    }
+}
+
+impl<P: Fp12Parameters> CanonicalDeserialize for Gt<P> {
+    #[inline]
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let m = match compress {
+            Compress::No => Fp12::deserialize_with_mode(&mut reader, compress, Validate::No)?,
+            Compress::Yes => {
+                let (g, flags): (Fp6<P::Fp6Params>, GTFlags) = Fp6::deserialize_with_flags(&mut reader)?;
+                match flags {
+                    GTFlags::One => Fp12::one(),
+                    GTFlags::MinusOne => -Fp12::one(),
+                    // m = (g + w) / (g - w).
+                    GTFlags::Full => {
+                        let numerator = Fp12::new(g, Fp6::one());
+                        let denominator = Fp12::new(g, -Fp6::one());
+                        numerator * denominator.inverse().ok_or(SerializationError::InvalidData)?
+                    }
+                }
+            }
+        };
+
+        let result = Self(m);
+        if let Validate::Yes = validate {
+            result.check()?;
+        }
+        Ok(result)
+    }
+}
+
+// The `*_native` helpers above are always compiled (not gated on `blst`),
+// so these tests build with either feature set. With the `blst` feature
+// (the default) they cross-check the helpers against the blst-backed trait
+// methods for bit-exact agreement; without it the trait methods are the
+// helpers themselves, so each test compares the native path with itself.
+#[cfg(test)]
+mod blst_cross_validation {
+    use super::*;
+    use snarkvm_curves::bls12_377::Fq12Parameters;
+
+    type TestFp12 = Fp12<Fq12Parameters>;
+
+    fn rand_fp12(rng: &mut impl Rng) -> TestFp12 {
+        TestFp12::rand(rng)
+    }
+
+    #[test]
+    fn conjugate_matches_native() {
+        let rng = &mut rand::thread_rng();
+        for _ in 0..100 {
+            let a = rand_fp12(rng);
+            let mut via_blst = a;
+            via_blst.conjugate();
+            let mut via_native = a;
+            via_native.conjugate_native();
+            assert_eq!(via_blst, via_native);
+        }
+    }
+
+    #[test]
+    fn square_matches_native() {
+        let rng = &mut rand::thread_rng();
+        for _ in 0..100 {
+            let a = rand_fp12(rng);
+            assert_eq!(a.square(), a.square_native());
+        }
+    }
+
+    #[test]
+    fn cyclotomic_square_matches_native() {
+        let rng = &mut rand::thread_rng();
+        for _ in 0..100 {
+            let a = rand_fp12(rng);
+            assert_eq!(a.cyclotomic_square(), a.cyclotomic_square_native());
+        }
+    }
+
+    #[test]
+    fn inverse_matches_native() {
+        let rng = &mut rand::thread_rng();
+        for _ in 0..100 {
+            let a = rand_fp12(rng);
+            assert_eq!(a.inverse(), a.inverse_native());
+        }
+    }
+
+    #[test]
+    fn mul_matches_native() {
+        let rng = &mut rand::thread_rng();
+        for _ in 0..100 {
+            let a = rand_fp12(rng);
+            let b = rand_fp12(rng);
+            assert_eq!(a * &b, a.mul_native(&b));
+        }
+    }
+
+    #[test]
+    fn is_one_matches_native() {
+        let rng = &mut rand::thread_rng();
+        assert_eq!(TestFp12::one().is_one(), TestFp12::one().is_one_native());
+        for _ in 0..100 {
+            let a = rand_fp12(rng);
+            assert_eq!(a.is_one(), a.is_one_native());
+        }
+    }
+
+    #[test]
+    fn frobenius_map_matches_native() {
+        let rng = &mut rand::thread_rng();
+        for _ in 0..100 {
+            let a = rand_fp12(rng);
+            for power in 0..12 {
+                let mut via_blst = a;
+                via_blst.frobenius_map(power);
+                let mut via_native = a;
+                via_native.frobenius_map_native(power);
+                assert_eq!(via_blst, via_native);
+            }
+        }
+    }
+}